@@ -0,0 +1,262 @@
+//! Support for listing the contents of an archive file (`.tar`, `.tar.gz`,
+//! `.zip`) as though it were a directory on disk.
+//!
+//! Output code in this crate (`print_entry` in `main.rs`) is written against
+//! whatever implements `Filelike`, not against `fs::file::File` directly.
+//! `File` is one implementor; this module adds a second one, `ArchiveMember`,
+//! so an archive’s entries go through the same printing code as files on
+//! disk.
+//!
+//! Listing an archive is opt-in, behind the `--archive` flag: `main.rs`
+//! recognises `.tar`/`.tar.gz`/`.tgz`/`.zip` extensions and, when that flag
+//! is given, calls `read_archive` instead of `File::from_path`.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::fs::fields::{Permissions, Size, Time, Type};
+
+
+/// The kind of archive format a path appears to hold, judged purely from its
+/// extension. We never sniff file contents here — by the time we’re asked to
+/// read an archive, the `info` layer has already decided it’s one.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum ArchiveKind {
+    Tar,
+    TarGz,
+    Zip,
+}
+
+impl ArchiveKind {
+
+    /// Guesses the archive kind from a path’s extension(s), returning `None`
+    /// for anything that isn’t a recognised archive.
+    pub fn detect(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_str()?.to_ascii_lowercase();
+
+        if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Some(Self::TarGz)
+        }
+        else if name.ends_with(".tar") {
+            Some(Self::Tar)
+        }
+        else if name.ends_with(".zip") {
+            Some(Self::Zip)
+        }
+        else {
+            None
+        }
+    }
+}
+
+
+/// A single entry read out of an archive: either a real member, or a
+/// synthetic directory we invented to group members that share a path
+/// prefix, so the hierarchy works in `--tree` view the same way a directory
+/// of directories would.
+pub struct ArchiveMember {
+    path: PathBuf,
+    file_type: Type,
+    size: Size,
+    time: Option<Time>,
+    permissions: Option<Permissions>,
+}
+
+impl ArchiveMember {
+    fn directory(path: PathBuf) -> Self {
+        Self { path, file_type: Type::Directory, size: Size::None, time: None, permissions: None }
+    }
+}
+
+
+/// The common interface between a real on-disk `fs::File` and an
+/// `ArchiveMember`, so `output`’s rendering code can be written once and
+/// driven by either.
+pub trait Filelike {
+
+    /// This entry’s full virtual path (on disk, or within the archive).
+    fn path(&self) -> &Path;
+
+    /// Just the last component of `path`, for display.
+    fn filename(&self) -> &str {
+        self.path().file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("?")
+    }
+
+    fn file_type(&self) -> Type;
+
+    fn size(&self) -> Size;
+
+    /// The modification time, if the underlying entry records one.
+    fn modified_time(&self) -> Option<Time>;
+
+    /// The Unix-style permission triad, if the underlying entry records one.
+    /// Tar headers carry a mode, which is decoded; zip entries’ stored modes
+    /// are not read, and synthesised directories have none, so this is
+    /// `None` for those.
+    fn permissions(&self) -> Option<Permissions>;
+
+    fn is_directory(&self) -> bool {
+        matches!(self.file_type(), Type::Directory)
+    }
+}
+
+impl Filelike for ArchiveMember {
+    fn path(&self) -> &Path { &self.path }
+    fn file_type(&self) -> Type { self.file_type }
+    fn size(&self) -> Size { self.size }
+    fn modified_time(&self) -> Option<Time> { self.time }
+    fn permissions(&self) -> Option<Permissions> { self.permissions }
+}
+
+
+/// Reads every entry out of the archive at `path`, returning one
+/// `ArchiveMember` per real entry plus one synthetic directory member for
+/// every path prefix that doesn’t otherwise appear (so `a/b/c.txt` with no
+/// entry for `a/` or `a/b/` still lists `a` and `a/b` as directories).
+pub fn read_archive(path: &Path) -> io::Result<Vec<ArchiveMember>> {
+    let members = match ArchiveKind::detect(path) {
+        Some(ArchiveKind::Tar)   => read_tar(io::BufReader::new(std::fs::File::open(path)?))?,
+        Some(ArchiveKind::TarGz) => read_tar(flate2::read::GzDecoder::new(std::fs::File::open(path)?))?,
+        Some(ArchiveKind::Zip)   => read_zip(std::fs::File::open(path)?)?,
+        None => return Err(io::Error::new(io::ErrorKind::InvalidInput, "Not a recognised archive")),
+    };
+
+    Ok(fill_in_parent_directories(members))
+}
+
+fn read_tar<R: io::Read>(reader: R) -> io::Result<Vec<ArchiveMember>> {
+    let mut archive = tar::Archive::new(reader);
+    let mut members = Vec::new();
+
+    for entry in archive.entries()? {
+        let entry = entry?;
+        let header = entry.header();
+
+        let file_type = match header.entry_type() {
+            tar::EntryType::Directory => Type::Directory,
+            tar::EntryType::Symlink   => Type::Link,
+            _                         => Type::File,
+        };
+
+        let mtime = header.mtime().ok().map(|seconds| Time { seconds: seconds as i64, nanoseconds: 0 });
+        let permissions = header.mode().ok().map(permissions_from_unix_mode);
+
+        members.push(ArchiveMember {
+            path: entry.path()?.into_owned(),
+            file_type,
+            size: if file_type.is_regular_file() { Size::Some(header.size().unwrap_or(0)) } else { Size::None },
+            time: mtime,
+            permissions,
+        });
+    }
+
+    Ok(members)
+}
+
+fn read_zip<R: io::Read + io::Seek>(reader: R) -> io::Result<Vec<ArchiveMember>> {
+    let mut archive = zip::ZipArchive::new(reader).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let mut members = Vec::with_capacity(archive.len());
+
+    for index in 0 .. archive.len() {
+        let entry = archive.by_index(index).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let path = match entry.enclosed_name() {
+            Some(p) => p.to_path_buf(),
+            None => continue, // Skip entries with unsafe (path-traversal) names.
+        };
+
+        let file_type = if entry.is_dir() { Type::Directory } else { Type::File };
+
+        let time = Some(Time {
+            seconds: zip_datetime_to_unix_seconds(&entry.last_modified()),
+            nanoseconds: 0,
+        });
+
+        members.push(ArchiveMember {
+            path,
+            file_type,
+            size: if file_type.is_regular_file() { Size::Some(entry.size()) } else { Size::None },
+            time,
+            // The stored Unix mode (if any) lives in zip’s external attributes;
+            // left unset here since not every zip writer populates it.
+            permissions: None,
+        });
+    }
+
+    Ok(members)
+}
+
+/// Converts a `zip::DateTime` (MS-DOS precision, 2-second granularity, no
+/// timezone — treated as UTC) into Unix seconds, without pulling in the
+/// crate's optional `time` feature just for this one conversion.
+fn zip_datetime_to_unix_seconds(time: &zip::DateTime) -> i64 {
+    /// Days elapsed since the Unix epoch at the start of `(year, month, day)`,
+    /// using the standard civil-calendar algorithm (Howard Hinnant's
+    /// `days_from_civil`).
+    fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+        let y = if month <= 2 { year - 1 } else { year };
+        let era = if y >= 0 { y } else { y - 399 } / 400;
+        let yoe = y - era * 400;
+        let mp = (month + 9) % 12;
+        let doy = (153 * mp + 2) / 5 + day - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146097 + doe - 719468
+    }
+
+    let days = days_from_civil(time.year() as i64, time.month() as i64, time.day() as i64);
+    let seconds_of_day = i64::from(time.hour()) * 3600 + i64::from(time.minute()) * 60 + i64::from(time.second());
+    days * 86400 + seconds_of_day
+}
+
+/// Turns a tar member’s Unix mode bits into the same `Permissions` triad
+/// that `fs::fields` uses for on-disk Unix files, so the details view can
+/// render both with identical code.
+///
+/// `pub(crate)` so `fs::file::File` can reuse it for real on-disk Unix files
+/// instead of duplicating the bit-for-bit translation.
+pub(crate) fn permissions_from_unix_mode(mode: u32) -> Permissions {
+    Permissions {
+        user_read:     mode & 0o400 != 0,
+        user_write:    mode & 0o200 != 0,
+        user_execute:  mode & 0o100 != 0,
+
+        group_read:    mode & 0o040 != 0,
+        group_write:   mode & 0o020 != 0,
+        group_execute: mode & 0o010 != 0,
+
+        other_read:    mode & 0o004 != 0,
+        other_write:   mode & 0o002 != 0,
+        other_execute: mode & 0o001 != 0,
+
+        setuid: mode & 0o4000 != 0,
+        setgid: mode & 0o2000 != 0,
+        sticky: mode & 0o1000 != 0,
+    }
+}
+
+/// Synthesises a directory `ArchiveMember` for every path prefix that has
+/// members but no entry of its own, so `--tree` has something to hang
+/// children off even when the archive never stored a bare directory entry
+/// for that prefix (which `zip` in particular often omits).
+fn fill_in_parent_directories(mut members: Vec<ArchiveMember>) -> Vec<ArchiveMember> {
+    use std::collections::BTreeSet;
+
+    let mut known: BTreeSet<PathBuf> = members.iter().map(|m| m.path.clone()).collect();
+    let mut synthesized = Vec::new();
+
+    for member in &members {
+        let mut ancestor = member.path.parent();
+        while let Some(dir) = ancestor {
+            if dir.as_os_str().is_empty() || !known.insert(dir.to_path_buf()) {
+                break;
+            }
+            synthesized.push(ArchiveMember::directory(dir.to_path_buf()));
+            ancestor = dir.parent();
+        }
+    }
+
+    members.append(&mut synthesized);
+    members
+}
@@ -15,7 +15,10 @@
 #![allow(non_camel_case_types)]
 #![allow(clippy::struct_excessive_bools)]
 
+#[cfg(windows)]
 use self::windows::NamedSecurityInfo;
+#[cfg(windows)]
+pub use self::windows::Stream;
 
 
 /// The type of a file’s block count.
@@ -38,35 +41,117 @@ pub type time_t = i64;
 pub type uid_t = u32;
 
 #[cfg(windows)]
-mod windows {
+pub(crate) mod windows {
     use std::convert::TryFrom;
     use std::{path::Path, os::windows::prelude::OsStrExt};
     use std::io;
+    use std::mem;
+    use std::collections::HashMap;
+    use std::sync::{Mutex, OnceLock};
 
     use windows::core::{PCWSTR, PWSTR};
     use windows::Win32::System::Memory::LocalFree;
-    use windows::Win32::Foundation::{PSID, GetLastError};
-    use windows::Win32::Security::{PSECURITY_DESCRIPTOR, OWNER_SECURITY_INFORMATION, GROUP_SECURITY_INFORMATION, LookupAccountSidW, SidTypeUnknown};
+    use windows::Win32::Foundation::{PSID, GetLastError, BOOL, CloseHandle};
+    use windows::Win32::Security::{
+        PSECURITY_DESCRIPTOR, OWNER_SECURITY_INFORMATION, GROUP_SECURITY_INFORMATION,
+        DACL_SECURITY_INFORMATION, LookupAccountSidW, SidTypeUnknown, EqualSid,
+        CreateWellKnownSid, WinWorldSid, ConvertSidToStringSidW,
+        GetSecurityDescriptorDacl, GetAclInformation, AclSizeInformation, GetAce,
+        ACL, ACL_SIZE_INFORMATION, ACE_HEADER, ACCESS_ALLOWED_ACE,
+        ACCESS_ALLOWED_ACE_TYPE, ACCESS_DENIED_ACE_TYPE,
+    };
     use windows::Win32::Security::Authorization::{SE_OBJECT_TYPE, SE_FILE_OBJECT, GetNamedSecurityInfoW};
+    use windows::Win32::Storage::FileSystem::{
+        FILE_GENERIC_READ, FILE_GENERIC_WRITE, FILE_GENERIC_EXECUTE,
+        FindFirstStreamW, FindNextStreamW, FindStreamInfoStandard, WIN32_FIND_STREAM_DATA,
+        GetFileAttributesW, FILE_ATTRIBUTE_ARCHIVE, FILE_ATTRIBUTE_DIRECTORY,
+        FILE_ATTRIBUTE_READONLY, FILE_ATTRIBUTE_HIDDEN, FILE_ATTRIBUTE_SYSTEM,
+        FILE_ATTRIBUTE_REPARSE_POINT, INVALID_FILE_ATTRIBUTES,
+    };
+
+    use super::{Permissions, Attributes};
+
+    /// Reads the file’s `FileAttributes` bitfield and decodes it into the
+    /// coarse `Attributes` flags exa already shows on Windows.
+    pub fn attributes(p: &Path) -> io::Result<Attributes> {
+        let object_name: Vec<u16> = p.as_os_str().encode_wide().chain(Some(0)).collect();
+        let raw = unsafe { GetFileAttributesW(PCWSTR(object_name.as_ptr())) };
+
+        if raw == INVALID_FILE_ATTRIBUTES {
+            let error = unsafe { GetLastError() };
+            return Err(io::Error::new(io::ErrorKind::NotFound, error.0.to_string()));
+        }
+
+        Ok(Attributes {
+            archive:       raw & FILE_ATTRIBUTE_ARCHIVE.0 != 0,
+            directory:     raw & FILE_ATTRIBUTE_DIRECTORY.0 != 0,
+            readonly:      raw & FILE_ATTRIBUTE_READONLY.0 != 0,
+            hidden:        raw & FILE_ATTRIBUTE_HIDDEN.0 != 0,
+            system:        raw & FILE_ATTRIBUTE_SYSTEM.0 != 0,
+            reparse_point: raw & FILE_ATTRIBUTE_REPARSE_POINT.0 != 0,
+        })
+    }
+
+    /// Allocates a zeroed buffer of at least `byte_len` bytes, aligned to 8
+    /// bytes. Several Win32 calls (`GetTokenInformation`, `CreateWellKnownSid`)
+    /// write a `SID`- or `TOKEN_USER`-shaped structure into caller-supplied
+    /// memory; those structs contain `u32`/pointer fields, so a plain
+    /// `Vec<u8>` (which the allocator only guarantees to `u8`'s 1-byte
+    /// alignment) is not aligned enough for them. Backing the buffer with `u64`s
+    /// instead guarantees the alignment those structs need.
+    pub(crate) fn aligned_buffer(byte_len: usize) -> Vec<u64> {
+        vec![0u64; (byte_len + 7) / 8]
+    }
+
+    /// Caches the `(name, domain)` pair already resolved for a SID, keyed by
+    /// the SID's canonical string form (`S-1-5-21-...`), mirroring the Unix
+    /// `users` cache. A directory full of files owned by the same handful of
+    /// accounts only pays for one domain round-trip per account. Failed
+    /// lookups are cached too (as `None`), so a broken SID isn't retried for
+    /// every file that carries it.
+    static SID_NAME_CACHE: OnceLock<Mutex<HashMap<String, Option<(String, String)>>>> = OnceLock::new();
+
+    fn sid_name_cache() -> &'static Mutex<HashMap<String, Option<(String, String)>>> {
+        SID_NAME_CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+    }
+
+    /// Converts a SID to its canonical string form (e.g. `S-1-5-21-...`), for
+    /// use as a cache key.
+    fn sid_to_string(sid: PSID) -> Result<String, io::Error> {
+        let mut buffer = PWSTR::null();
+        let return_value = unsafe { ConvertSidToStringSidW(sid, &mut buffer) };
+
+        if return_value != true {
+            let error = unsafe { GetLastError() };
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, error.0.to_string()));
+        }
+
+        let string = unsafe { buffer.to_string() }
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()));
+        unsafe { LocalFree(buffer.0 as isize) };
+        string
+    }
 
     pub struct NamedSecurityInfo {
         pub owner: PSID,
         pub group: PSID,
         pub security_descriptor: PSECURITY_DESCRIPTOR,
+        pub dacl: *mut ACL,
+        pub dacl_present: bool,
     }
 
     impl NamedSecurityInfo {
         /// Look up the character count of the username and domain name
         /// so that we can construct buffers of adequate size.
-        /// 
+        ///
         /// Returns `(username_character_count, domain_name_character_count)`
-        pub fn lookup_account_sid_buffer(&self) -> Result<(u32, u32), io::Error> {
+        fn lookup_account_sid_buffer(sid: PSID) -> Result<(u32, u32), io::Error> {
             let mut name_character_count = 0;
             let mut domain_name_character_count = 0;
             let return_value = unsafe {
                 LookupAccountSidW(
                     PCWSTR(std::ptr::null()), // Local computer
-                    self.owner, // The SID we want to look up
+                    sid, // The SID we want to look up
                     PWSTR(std::ptr::null_mut()), // No buffer constructed yet
                     &mut name_character_count, // The number of characters we need to store in our username buffer
                     PWSTR(std::ptr::null_mut()), // No buffer constructed yet
@@ -86,10 +171,11 @@ mod windows {
             Ok((name_character_count, domain_name_character_count))
         }
 
-        /// Returns the (username, domain name) of the SID we give it.
-        pub fn lookup_account_sid(&self) -> Result<(String, String), io::Error> {
+        /// Returns the (username, domain name) of the given SID, making the
+        /// two `LookupAccountSidW` calls directly (no cache).
+        fn lookup_account_sid_uncached(sid: PSID) -> Result<(String, String), io::Error> {
             // Get the buffer sizes.
-            let (mut name_character_count, mut domain_name_character_count) = self.lookup_account_sid_buffer()?;
+            let (mut name_character_count, mut domain_name_character_count) = Self::lookup_account_sid_buffer(sid)?;
 
             // Make the buffers.
             let mut name_buffer = Vec::with_capacity(name_character_count as usize);
@@ -99,7 +185,7 @@ mod windows {
             let return_value = unsafe {
                 LookupAccountSidW(
                     PCWSTR(std::ptr::null()),
-                    self.owner,
+                    sid,
                     PWSTR(name_buffer.as_mut_ptr()),
                     &mut name_character_count,
                     PWSTR(domain_name_buffer.as_mut_ptr()),
@@ -125,8 +211,128 @@ mod windows {
                 String::from_utf16_lossy(&domain_name_buffer),
             ))
         }
+
+        /// Returns the (username, domain name) of the file's owner (or, if
+        /// `is_group` is `true`, its group), going through the process-wide
+        /// SID-to-name cache so that a SID shared by many files in a listing
+        /// is only ever resolved once.
+        pub fn lookup_account_sid(&self, is_group: bool) -> Result<(String, String), io::Error> {
+            let sid = if is_group { self.group } else { self.owner };
+            let key = sid_to_string(sid)?;
+
+            if let Some(cached) = sid_name_cache().lock().unwrap().get(&key) {
+                return cached.clone().ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::NotFound, format!("SID {key} previously failed to resolve"))
+                });
+            }
+
+            let resolved = Self::lookup_account_sid_uncached(sid);
+            sid_name_cache().lock().unwrap().insert(key, resolved.as_ref().ok().cloned());
+            resolved
+        }
+
+        /// Builds a synthesized Unix-style `rwx` triad (user/group/other) out of
+        /// the file’s DACL, so the existing `Permissions`/`OctalPermissions`
+        /// rendering path can be reused on Windows.
+        ///
+        /// A NULL DACL means “everyone has full access”, so every bit is set.
+        /// An empty, present DACL means nobody (beyond what Windows implicitly
+        /// grants) has access, so every bit is left unset.
+        ///
+        /// Rather than folding ACEs into the result bit-by-bit in ACE order
+        /// (which would only be correct for a canonical ACL, where every deny
+        /// precedes the allows for the same trustee), we accumulate separate
+        /// allow/deny masks per trustee category and apply the deny mask last.
+        /// That gives the same answer as Windows' own access check for any
+        /// ACE ordering.
+        pub fn permissions(&self) -> Permissions {
+            if !self.dacl_present || self.dacl.is_null() {
+                // A present-but-NULL DACL grants everyone full access; an
+                // absent DACL (shouldn't happen, since we always request one)
+                // is treated the same as "no access" below.
+                return if self.dacl_present { Permissions::all() } else { Permissions::none() };
+            }
+
+            let mut everyone_sid_buffer = aligned_buffer(256);
+            let mut everyone_sid_size = (everyone_sid_buffer.len() * mem::size_of::<u64>()) as u32;
+            let everyone_sid = PSID(everyone_sid_buffer.as_mut_ptr().cast());
+            let have_everyone_sid = unsafe {
+                CreateWellKnownSid(WinWorldSid, PSID::default(), everyone_sid, &mut everyone_sid_size)
+            } == true;
+
+            let mut acl_size_info = ACL_SIZE_INFORMATION::default();
+            let got_size_info = unsafe {
+                GetAclInformation(
+                    self.dacl,
+                    &mut acl_size_info as *mut _ as *mut std::ffi::c_void,
+                    mem::size_of::<ACL_SIZE_INFORMATION>() as u32,
+                    AclSizeInformation,
+                )
+            } == true;
+
+            if !got_size_info {
+                return Permissions::none();
+            }
+
+            let (mut user_allow, mut user_deny) = (0u32, 0u32);
+            let (mut group_allow, mut group_deny) = (0u32, 0u32);
+            let (mut other_allow, mut other_deny) = (0u32, 0u32);
+
+            for index in 0..acl_size_info.AceCount {
+                let mut ace_ptr: *mut std::ffi::c_void = std::ptr::null_mut();
+                let got_ace = unsafe { GetAce(self.dacl, index, &mut ace_ptr) } == true;
+                if !got_ace || ace_ptr.is_null() {
+                    continue;
+                }
+
+                let header = unsafe { &*(ace_ptr as *const ACE_HEADER) };
+                let is_allow = header.AceType == ACCESS_ALLOWED_ACE_TYPE as u8;
+                let is_deny = header.AceType == ACCESS_DENIED_ACE_TYPE as u8;
+                if !is_allow && !is_deny {
+                    continue;
+                }
+
+                // `ACCESS_ALLOWED_ACE` and `ACCESS_DENIED_ACE` share the same
+                // layout (header, mask, then the trustee SID), so either type
+                // can be read through the same struct.
+                let ace = unsafe { &*(ace_ptr as *const ACCESS_ALLOWED_ACE) };
+                let mask = ace.Mask;
+                let trustee = PSID((&ace.SidStart as *const u32) as *mut std::ffi::c_void);
+
+                let is_owner = unsafe { EqualSid(trustee, self.owner) } == true;
+                let is_group = unsafe { EqualSid(trustee, self.group) } == true;
+                let is_everyone = have_everyone_sid && unsafe { EqualSid(trustee, everyone_sid) } == true;
+
+                if is_owner    { if is_allow { user_allow  |= mask; } if is_deny { user_deny  |= mask; } }
+                if is_group    { if is_allow { group_allow |= mask; } if is_deny { group_deny  |= mask; } }
+                if is_everyone { if is_allow { other_allow |= mask; } if is_deny { other_deny  |= mask; } }
+            }
+
+            let user_mask = user_allow & !user_deny;
+            let group_mask = group_allow & !group_deny;
+            let other_mask = other_allow & !other_deny;
+
+            Permissions {
+                // The three generic masks share bits (SYNCHRONIZE,
+                // READ_CONTROL), so a letter is only set when every bit of
+                // its mask survives.
+                user_read:     user_mask & FILE_GENERIC_READ.0 == FILE_GENERIC_READ.0,
+                user_write:    user_mask & FILE_GENERIC_WRITE.0 == FILE_GENERIC_WRITE.0,
+                user_execute:  user_mask & FILE_GENERIC_EXECUTE.0 == FILE_GENERIC_EXECUTE.0,
+
+                group_read:    group_mask & FILE_GENERIC_READ.0 == FILE_GENERIC_READ.0,
+                group_write:   group_mask & FILE_GENERIC_WRITE.0 == FILE_GENERIC_WRITE.0,
+                group_execute: group_mask & FILE_GENERIC_EXECUTE.0 == FILE_GENERIC_EXECUTE.0,
+
+                other_read:    other_mask & FILE_GENERIC_READ.0 == FILE_GENERIC_READ.0,
+                other_write:   other_mask & FILE_GENERIC_WRITE.0 == FILE_GENERIC_WRITE.0,
+                other_execute: other_mask & FILE_GENERIC_EXECUTE.0 == FILE_GENERIC_EXECUTE.0,
+
+                sticky: false, setgid: false, setuid: false,
+            }
+        }
     }
-    
+
     impl TryFrom<&Path> for NamedSecurityInfo {
         type Error = std::io::Error;
 
@@ -134,14 +340,14 @@ mod windows {
             let object_name: Vec<u16> = p.as_os_str().encode_wide().chain(Some(0)).collect();
             let p_object_name = PCWSTR(object_name.as_ptr());
             let object_type: SE_OBJECT_TYPE = SE_FILE_OBJECT;
-            let security_info = OWNER_SECURITY_INFORMATION | GROUP_SECURITY_INFORMATION;
+            let security_info = OWNER_SECURITY_INFORMATION | GROUP_SECURITY_INFORMATION | DACL_SECURITY_INFORMATION;
             let mut sid_owner = PSID(std::ptr::null_mut());
             let mut sid_group = PSID(std::ptr::null_mut());
             let mut security_descriptor = PSECURITY_DESCRIPTOR::default();
 
             unsafe {
                 GetNamedSecurityInfoW(
-                    p_object_name, 
+                    p_object_name,
                     object_type,
                     security_info,
                     &mut sid_owner,
@@ -150,7 +356,7 @@ mod windows {
                     std::ptr::null_mut(),
                     &mut security_descriptor);
             }
-            
+
 
             if sid_owner.is_invalid() {
                 return Err(io::Error::new(io::ErrorKind::NotFound, "Owner SID not found"));
@@ -164,11 +370,20 @@ mod windows {
                 return Err(io::Error::new(io::ErrorKind::PermissionDenied, "Security Descriptor Inaccessible"));
             }
 
+            let mut dacl_present = BOOL(0);
+            let mut dacl: *mut ACL = std::ptr::null_mut();
+            let mut dacl_defaulted = BOOL(0);
+            unsafe {
+                GetSecurityDescriptorDacl(security_descriptor, &mut dacl_present, &mut dacl, &mut dacl_defaulted);
+            }
+
             Ok(
                 NamedSecurityInfo {
                     owner: sid_owner,
                     group: sid_group,
                     security_descriptor,
+                    dacl,
+                    dacl_present: dacl_present.as_bool(),
                 }
             )
         }
@@ -182,6 +397,81 @@ mod windows {
         }
     }
 
+
+    /// A single NTFS Alternate Data Stream attached to a file — the Windows
+    /// analogue of a Unix extended attribute. Every file has an unnamed
+    /// `::$DATA` stream holding its normal contents; anything beyond that
+    /// (such as the `Zone.Identifier` mark Windows attaches to downloaded
+    /// files) shows up here.
+    pub struct Stream {
+        pub name: String,
+        pub size: u64,
+    }
+
+    /// Lists the named streams attached to `p`, excluding the default
+    /// `::$DATA` stream that every file already has. An empty result means
+    /// the file has no extended-attribute-like data to show.
+    pub fn streams(p: &Path) -> io::Result<Vec<Stream>> {
+        let object_name: Vec<u16> = p.as_os_str().encode_wide().chain(Some(0)).collect();
+        let mut find_data = WIN32_FIND_STREAM_DATA::default();
+
+        let handle = unsafe {
+            FindFirstStreamW(PCWSTR(object_name.as_ptr()), FindStreamInfoStandard, &mut find_data as *mut _ as *mut _, 0)
+        };
+
+        let handle = match handle {
+            Ok(h) if !h.is_invalid() => h,
+            // No streams, or the filesystem doesn’t support them — either way
+            // there’s nothing extra to show.
+            _ => return Ok(Vec::new()),
+        };
+
+        let mut found = Vec::new();
+        loop {
+            if let Some(stream) = parse_find_stream_data(&find_data) {
+                found.push(stream);
+            }
+
+            let more = unsafe { FindNextStreamW(handle, &mut find_data as *mut _ as *mut _) };
+            if more != true {
+                break;
+            }
+        }
+
+        unsafe { let _ = CloseHandle(handle); }
+
+        Ok(found)
+    }
+
+    /// Whether `p` carries any named streams beyond its default data stream,
+    /// for the `@` indicator that `PermissionsPlus::xattrs` drives.
+    pub fn has_named_streams(p: &Path) -> bool {
+        streams(p).map(|found| !found.is_empty()).unwrap_or(false)
+    }
+
+    fn parse_find_stream_data(data: &WIN32_FIND_STREAM_DATA) -> Option<Stream> {
+        let name_len = data.cStreamName.iter().position(|&c| c == 0).unwrap_or(data.cStreamName.len());
+        let raw_name = String::from_utf16_lossy(&data.cStreamName[..name_len]);
+
+        // The default stream is reported as `::$DATA`; that’s not “extra”
+        // data, so it’s filtered out here rather than by every caller.
+        if raw_name == "::$DATA" {
+            return None;
+        }
+
+        // Named streams come back decorated as `:name:$DATA` (the leading
+        // colon is the empty default-attribute name, `$DATA` the attribute
+        // type); strip both so `name` is just what `Zone.Identifier`-style
+        // tools actually call the stream.
+        let name = raw_name
+            .strip_prefix(':')
+            .and_then(|s| s.strip_suffix(":$DATA"))
+            .unwrap_or(&raw_name)
+            .to_owned();
+
+        Some(Stream { name, size: data.StreamSize as u64 })
+    }
+
 }
 
 /// The file’s base type, which gets displayed in the very first column of the
@@ -231,6 +521,31 @@ pub struct Permissions {
     pub setuid:         bool,
 }
 
+impl Permissions {
+    /// No bits set for anyone — the synthesized triad for an empty DACL.
+    #[cfg(windows)]
+    fn none() -> Self {
+        Self {
+            user_read: false, user_write: false, user_execute: false,
+            group_read: false, group_write: false, group_execute: false,
+            other_read: false, other_write: false, other_execute: false,
+            sticky: false, setgid: false, setuid: false,
+        }
+    }
+
+    /// Every `rwx` bit set for everyone — the synthesized triad for a NULL
+    /// (everyone-has-full-access) DACL.
+    #[cfg(windows)]
+    fn all() -> Self {
+        Self {
+            user_read: true, user_write: true, user_execute: true,
+            group_read: true, group_write: true, group_execute: true,
+            other_read: true, other_write: true, other_execute: true,
+            sticky: false, setgid: false, setuid: false,
+        }
+    }
+}
+
 /// The file's FileAttributes field, available only on Windows.
 #[derive(Copy, Clone)]
 pub struct Attributes {
@@ -248,13 +563,41 @@ pub struct Attributes {
 #[derive(Copy, Clone)]
 pub struct PermissionsPlus {
     pub file_type:   Type,
-    #[cfg(unix)]
     pub permissions: Permissions,
     #[cfg(windows)]
     pub attributes:  Attributes,
+
+    /// Whether this file carries extended attributes — on Unix, real xattrs;
+    /// on Windows, named Alternate Data Streams beyond the default `::$DATA`
+    /// one (see `windows::has_named_streams`). Either way, it drives the `@`
+    /// indicator in the details view.
     pub xattrs:      bool,
 }
 
+#[cfg(windows)]
+impl PermissionsPlus {
+
+    /// Builds the Windows column-fusing `PermissionsPlus` for a path: reads
+    /// its DACL to synthesize the `rwx` triad (see
+    /// `windows::NamedSecurityInfo::permissions`), its `FileAttributes`
+    /// bitfield, and whether it carries named Alternate Data Streams (which
+    /// drives the `@` indicator, mirroring Unix xattrs).
+    ///
+    /// `file_type` comes from the caller (the `info` layer already has to
+    /// classify the entry to decide whether to recurse into it), so it isn’t
+    /// re-derived here.
+    pub fn for_path(path: &std::path::Path, file_type: Type) -> std::io::Result<Self> {
+        let security_info = windows::NamedSecurityInfo::try_from(path)?;
+
+        Ok(Self {
+            file_type,
+            permissions: security_info.permissions(),
+            attributes: windows::attributes(path)?,
+            xattrs: windows::has_named_streams(path),
+        })
+    }
+}
+
 
 /// The permissions encoded as octal values
 #[derive(Copy, Clone)]
@@ -307,10 +650,14 @@ pub struct User(pub uid_t);
 #[cfg(windows)]
 pub struct User(pub NamedSecurityInfo);
 
+#[cfg(unix)]
 /// The ID of the group that a file belongs to.
 #[derive(Copy, Clone)]
 pub struct Group(pub gid_t);
 
+#[cfg(windows)]
+pub struct Group(pub NamedSecurityInfo);
+
 
 /// A file’s size, in bytes. This is usually formatted by the `number_prefix`
 /// crate into something human-readable.
@@ -0,0 +1,77 @@
+//! A real, on-disk file — the other implementor of `archive::Filelike`,
+//! alongside `archive::ArchiveMember`, so the same rendering code can walk
+//! either a directory or an archive.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::fs::archive::Filelike;
+use crate::fs::fields::{Permissions, Size, Time, Type};
+
+/// A file (or directory, symlink, etc.) read straight off the filesystem.
+pub struct File {
+    path: PathBuf,
+    metadata: std::fs::Metadata,
+}
+
+impl File {
+    pub fn from_path(path: PathBuf) -> io::Result<Self> {
+        let metadata = std::fs::symlink_metadata(&path)?;
+        Ok(Self { path, metadata })
+    }
+
+    fn file_type_from_metadata(metadata: &std::fs::Metadata) -> Type {
+        if metadata.is_dir() {
+            Type::Directory
+        }
+        else if metadata.file_type().is_symlink() {
+            Type::Link
+        }
+        else {
+            Type::File
+        }
+    }
+
+    #[cfg(unix)]
+    fn permissions_impl(&self) -> Option<Permissions> {
+        use std::os::unix::fs::PermissionsExt;
+        Some(crate::fs::archive::permissions_from_unix_mode(self.metadata.permissions().mode()))
+    }
+
+    #[cfg(windows)]
+    fn permissions_impl(&self) -> Option<Permissions> {
+        super::fields::windows::NamedSecurityInfo::try_from(self.path.as_path())
+            .ok()
+            .map(|info| info.permissions())
+    }
+}
+
+impl Filelike for File {
+    fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn file_type(&self) -> Type {
+        Self::file_type_from_metadata(&self.metadata)
+    }
+
+    fn size(&self) -> Size {
+        if self.file_type().is_regular_file() {
+            Size::Some(self.metadata.len())
+        }
+        else {
+            Size::None
+        }
+    }
+
+    fn modified_time(&self) -> Option<Time> {
+        self.metadata.modified().ok().map(|t| {
+            let duration = t.duration_since(std::time::UNIX_EPOCH).unwrap_or_default();
+            Time { seconds: duration.as_secs() as crate::fs::fields::time_t, nanoseconds: duration.subsec_nanos() as crate::fs::fields::time_t }
+        })
+    }
+
+    fn permissions(&self) -> Option<Permissions> {
+        self.permissions_impl()
+    }
+}
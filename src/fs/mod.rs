@@ -0,0 +1,5 @@
+//! Everything to do with reading, representing, and classifying files.
+
+pub mod fields;
+pub mod archive;
+pub mod file;
@@ -0,0 +1,86 @@
+//! A minimal entry point wiring together the pieces in this tree: reading
+//! real files via `fs::file::File` and, behind `--archive`, archive members
+//! via `fs::archive::read_archive` — both through the shared `Filelike`
+//! interface.
+//!
+//! The full `options`/`output::details` layers that upstream exa uses to
+//! parse flags and lay out a details table aren't part of this checked-out
+//! tree, so this binary is deliberately small: it recognises just enough of
+//! the command line to prove the archive support is reachable, not a
+//! reimplementation of exa's whole CLI.
+//!
+//! Because of that, most of `fs::fields`'s wrapper types (`Git`, `Inode`,
+//! `User`, `Blocks`, ...) exist for a details/grid renderer that isn't
+//! checked into this tree yet, so this crate is allowed to carry dead code
+//! rather than trim public API that later requests will come back and wire
+//! up.
+#![allow(dead_code)]
+
+mod fs;
+mod output;
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use fs::archive::{self, ArchiveKind, Filelike};
+use fs::file::File;
+
+fn main() -> ExitCode {
+    let mut archive_flag = false;
+    let mut path = None;
+
+    for arg in std::env::args().skip(1) {
+        if arg == "--archive" {
+            archive_flag = true;
+        }
+        else {
+            path = Some(PathBuf::from(arg));
+        }
+    }
+
+    let Some(path) = path else {
+        eprintln!("Usage: exa [--archive] <path>");
+        return ExitCode::FAILURE;
+    };
+
+    // The `info` layer's job: decide whether this path should be read as an
+    // archive or as a plain file/directory.
+    if archive_flag && ArchiveKind::detect(&path).is_some() {
+        match archive::read_archive(&path) {
+            Ok(members) => {
+                for member in &members {
+                    print_entry(member);
+                }
+                ExitCode::SUCCESS
+            }
+            Err(e) => {
+                eprintln!("{}: {e}", path.display());
+                ExitCode::FAILURE
+            }
+        }
+    }
+    else {
+        match File::from_path(path.clone()) {
+            Ok(file) => {
+                print_entry(&file);
+                ExitCode::SUCCESS
+            }
+            Err(e) => {
+                eprintln!("{}: {e}", path.display());
+                ExitCode::FAILURE
+            }
+        }
+    }
+}
+
+/// Prints one line per entry, the same way regardless of whether it came
+/// from the filesystem or from inside an archive.
+fn print_entry(entry: &dyn Filelike) {
+    let size = match entry.size() {
+        fs::fields::Size::Some(bytes) => bytes.to_string(),
+        fs::fields::Size::None => "-".to_owned(),
+        fs::fields::Size::DeviceIDs(ids) => format!("{},{}", ids.major, ids.minor),
+    };
+
+    println!("{:>10}  {}", size, entry.path().display());
+}
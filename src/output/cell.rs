@@ -0,0 +1,30 @@
+//! The `TextCell`, a styled piece of text tagged with the display width it
+//! will occupy in the output once its ANSI colour codes are stripped out —
+//! the table layout needs that width to line up columns, but can't get it
+//! back out of an already-painted string.
+
+use ansi_term::{ANSIString, Style};
+use unicode_width::UnicodeWidthStr;
+
+/// The number of columns a cell's text will occupy once rendered. Distinct
+/// from the string's byte or `char` length, both because of multi-byte
+/// characters and because some codepoints (CJK, for instance) are
+/// double-width.
+pub type Width = usize;
+
+/// A single piece of a row: already-styled text, plus the display width it
+/// was measured at, so the table layout doesn't have to re-measure (and
+/// re-strip the ANSI codes from) every cell it lays out.
+#[derive(PartialEq, Debug, Clone)]
+pub struct TextCell {
+    pub contents: Vec<ANSIString<'static>>,
+    pub width: Width,
+}
+
+impl TextCell {
+    /// Paints `string` in `style`, measuring its on-screen width up front.
+    pub fn paint(style: Style, string: String) -> Self {
+        let width = UnicodeWidthStr::width(&*string);
+        Self { contents: vec![style.paint(string)], width }
+    }
+}
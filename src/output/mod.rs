@@ -0,0 +1,5 @@
+//! Formatting and laying out file listings for display.
+
+pub mod cell;
+pub mod render;
+pub mod table;
@@ -0,0 +1,14 @@
+//! Per-platform rendering helpers for columns that differ between Unix and
+//! Windows, such as ownership and extended attributes.
+//!
+//! Each of these depends on Windows-only pieces (Win32 account-name lookups,
+//! SID comparison, the NTFS stream list from `fs::fields::windows`), so
+//! they're only meaningful — and only buildable — on Windows; gate them behind `cfg(windows)` rather than compiling FFI
+//! calls that don't exist on this platform.
+
+#[cfg(windows)]
+pub mod groups_windows;
+#[cfg(windows)]
+pub mod streams_windows;
+#[cfg(windows)]
+pub mod users_windows;
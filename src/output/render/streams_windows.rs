@@ -0,0 +1,20 @@
+use ansi_term::Style;
+
+use crate::fs::fields as f;
+use crate::output::cell::TextCell;
+
+/// Renders the extended (`@`) listing of a file’s NTFS Alternate Data
+/// Streams — the Windows equivalent of a Unix extended-attribute listing —
+/// as one cell per stream, each a `name (size)` pair.
+///
+/// Its input is the list that `fs::fields::windows::streams` returns; no
+/// code in this crate calls it.
+pub fn render<C: Colours>(streams: &[f::Stream], colours: &C) -> Vec<TextCell> {
+    streams.iter()
+        .map(|stream| TextCell::paint(colours.stream_name(), format!("{} ({} bytes)", stream.name, stream.size)))
+        .collect()
+}
+
+pub trait Colours {
+    fn stream_name(&self) -> Style;
+}
@@ -1,97 +1,86 @@
 use ansi_term::Style;
-use windows::Win32::Foundation::{GetLastError};
-use windows::Win32::Security::{LookupAccountSidW, SidTypeUnknown};
-use windows::core::{PCWSTR, PWSTR};
-use std::io;
+use windows::Win32::Foundation::{CloseHandle, HANDLE};
+use windows::Win32::Security::{PSID, EqualSid, GetLengthSid, TOKEN_QUERY, TokenUser, TOKEN_USER, GetTokenInformation};
+use windows::Win32::System::Threading::{GetCurrentProcess, OpenProcessToken};
+use std::sync::OnceLock;
 use log::*;
 
 use crate::fs::fields as f;
 use crate::output::cell::TextCell;
 use crate::output::table::UserFormat;
 
-impl f::User {
-    pub fn render<C: Colours>(self, colours: &C, _format: UserFormat) -> TextCell {
-        let (display_name, style) = {
-            let result = self.lookup_account_sid();
-            if let Ok((user_name, domain_name)) = result {
-                ([domain_name, user_name].join("/"), colours.someone_else())
-            } else {
-                error!("Error looking up windows user name: {:?}", result);
-                ("ERROR".to_owned(), ansi_term::Colour::Red.bold())
-            }
-        };
+/// The SID of the user running exa, fetched from the process token once and
+/// cached for the lifetime of the run so every file doesn’t have to re-open
+/// its own token handle.
+static CURRENT_USER_SID: OnceLock<Option<Vec<u64>>> = OnceLock::new();
 
-        TextCell::paint(style, display_name)
-    }
-
-    /// Look up the character count of the username and domain name
-    /// so that we can construct buffers of adequate size.
-    /// 
-    /// Returns `(username_character_count, domain_name_character_count)`
-    fn lookup_account_sid_buffer(&self) -> Result<(u32, u32), io::Error> {
-        let mut name_character_count = 0;
-        let mut domain_name_character_count = 0;
-        let return_value = unsafe {
-            LookupAccountSidW(
-                PCWSTR(std::ptr::null()), // Local computer
-                self.0.owner, // The SID we want to look up
-                PWSTR(std::ptr::null_mut()), // No buffer constructed yet
-                &mut name_character_count, // The number of characters we need to store in our username buffer
-                PWSTR(std::ptr::null_mut()), // No buffer constructed yet
-                &mut domain_name_character_count, // The number of characters we need to store in our domain buffer
-                std::ptr::null_mut() // Unused
-            )
-        };
-
-        if return_value == true {
-            return Err(io::Error::new(io::ErrorKind::InvalidInput, "LookupAccountSidW suceeded with null buffers when it should have failed"));
+/// Returns the raw bytes (backed by a `u64`-aligned buffer) of the current
+/// process’s user SID, computing and caching it on first use.
+fn current_user_sid() -> &'static Option<Vec<u64>> {
+    CURRENT_USER_SID.get_or_init(|| unsafe {
+        let mut token = HANDLE::default();
+        if OpenProcessToken(GetCurrentProcess(), TOKEN_QUERY, &mut token) != true {
+            return None;
         }
 
-        if (name_character_count == 0) || (domain_name_character_count == 0) {
-            return Err(io::Error::new(io::ErrorKind::NotFound, "SID was incorrect, causing domain/name count to return 0"));
+        let mut size = 0u32;
+        // First call just asks for the buffer size we need to allocate.
+        let _ = GetTokenInformation(token, TokenUser, None, 0, &mut size);
+        if size == 0 {
+            let _ = CloseHandle(token);
+            return None;
         }
 
-        Ok((name_character_count, domain_name_character_count))
-    }
+        // `TOKEN_USER` contains pointer- and `u32`-sized fields, so the
+        // buffer GetTokenInformation writes into needs their alignment, not
+        // just `u8`'s — back it with `u64`s rather than a plain byte `Vec`.
+        let mut buffer = f::windows::aligned_buffer(size as usize);
+        let got = GetTokenInformation(token, TokenUser, Some(buffer.as_mut_ptr().cast()), size, &mut size);
+        let _ = CloseHandle(token);
 
-    /// Returns the (username, domain name) of the SID we give it.
-    fn lookup_account_sid(&self) -> Result<(String, String), io::Error> {
-        // Get the buffer sizes.
-        let (mut name_character_count, mut domain_name_character_count) = self.lookup_account_sid_buffer()?;
+        if got != true {
+            return None;
+        }
 
-        // Make the buffers.
-        let mut name_buffer = Vec::with_capacity(name_character_count as usize);
-        let mut domain_name_buffer = Vec::with_capacity(domain_name_character_count as usize);
+        let token_user = &*(buffer.as_ptr().cast::<TOKEN_USER>());
+        let sid = token_user.User.Sid;
+        let sid_len = GetLengthSid(sid) as usize;
 
-        let mut e_use = SidTypeUnknown;
-        let return_value = unsafe {
-            LookupAccountSidW(
-                PCWSTR(std::ptr::null()),
-                self.0.owner,
-                PWSTR(name_buffer.as_mut_ptr()),
-                &mut name_character_count,
-                PWSTR(domain_name_buffer.as_mut_ptr()),
-                &mut domain_name_character_count,
-                &mut e_use,
-            )
-        };
+        // `sid_bytes` is later handed back to `EqualSid` as a `PSID`, which
+        // reads through it as a `SID` (also alignment-sensitive), so it
+        // needs the same treatment.
+        let mut sid_bytes = f::windows::aligned_buffer(sid_len);
+        std::ptr::copy_nonoverlapping(sid.0.cast::<u8>(), sid_bytes.as_mut_ptr().cast::<u8>(), sid_len);
+        Some(sid_bytes)
+    })
+}
 
-        if return_value != true {
-            let error = unsafe { GetLastError() };
-            // TODO: FormatMessage
-            return Err(io::Error::new(io::ErrorKind::InvalidInput, error.0.to_string()));
+/// Whether the given owner SID belongs to the user running exa.
+fn is_current_user(owner: PSID) -> bool {
+    match current_user_sid() {
+        Some(sid_bytes) => {
+            let current_user = PSID(sid_bytes.as_ptr() as *mut _ as *mut std::ffi::c_void);
+            unsafe { EqualSid(owner, current_user) == true }
         }
+        None => false,
+    }
+}
 
-        // Set the buffer lengths to the bytes written by LookupAccountSidW
-        unsafe {
-            name_buffer.set_len(name_character_count as usize);
-            domain_name_buffer.set_len(domain_name_character_count as usize);
-        }
+impl f::User {
+    pub fn render<C: Colours>(self, colours: &C, _format: UserFormat) -> TextCell {
+        let style = if is_current_user(self.0.owner) { colours.you() } else { colours.someone_else() };
 
-        Ok((
-            String::from_utf16_lossy(&name_buffer),
-            String::from_utf16_lossy(&domain_name_buffer),
-        ))
+        let display_name = {
+            let result = self.0.lookup_account_sid(false);
+            if let Ok((user_name, domain_name)) = result {
+                [domain_name, user_name].join("/")
+            } else {
+                error!("Error looking up windows user name: {:?}", result);
+                return TextCell::paint(ansi_term::Colour::Red.bold(), "ERROR".to_owned());
+            }
+        };
+
+        TextCell::paint(style, display_name)
     }
 }
 
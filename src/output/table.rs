@@ -0,0 +1,10 @@
+//! Column-level formatting choices shared across the details view.
+
+/// How a user/group column should be rendered: the resolved name (the
+/// default), or the underlying numeric ID — the `-n`/`--numeric` behaviour
+/// Unix `ls` also offers.
+#[derive(PartialEq, Eq, Copy, Clone)]
+pub enum UserFormat {
+    Name,
+    Numeric,
+}